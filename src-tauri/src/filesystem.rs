@@ -393,52 +393,47 @@ fn calculate_dir_size_sync(dir: &PathBuf) -> Result<u64> {
     Ok(total_size)
 }
 
-/// Try to fix instance name by reading from manifest.json or minecraftinstance.json
-fn try_fix_instance_name(instance_dir: &std::path::Path, metadata: &mut InstanceMetadata) -> Result<bool> {
-    // Only try to fix if name is same as ID (which implies it's using the folder name)
-    if metadata.name != metadata.id {
-        return Ok(false);
-    }
-
-    let mut new_name = None;
-
-    // Try manifest.json first
-    let manifest_path = instance_dir.join("manifest.json");
-    if manifest_path.exists() {
-        if let Ok(content) = fs::read_to_string(&manifest_path) {
+/// Read the pack name from manifest.json or minecraftinstance.json in an instance directory
+fn read_instance_name_from_manifests(instance_dir: &std::path::Path) -> Option<String> {
+    // Try manifest.json first, then minecraftinstance.json
+    for file_name in ["manifest.json", "minecraftinstance.json"] {
+        let path = instance_dir.join(file_name);
+        if !path.exists() {
+            continue;
+        }
+        if let Ok(content) = fs::read_to_string(&path) {
             if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
                 if let Some(name) = json.get("name").and_then(|n| n.as_str()) {
-                    new_name = Some(name.to_string());
+                    return Some(name.to_string());
                 }
             }
         }
     }
 
-    // Try minecraftinstance.json if manifest.json didn't work
-    if new_name.is_none() {
-        let instance_cfg_path = instance_dir.join("minecraftinstance.json");
-        if instance_cfg_path.exists() {
-            if let Ok(content) = fs::read_to_string(&instance_cfg_path) {
-                if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-                    if let Some(name) = json.get("name").and_then(|n| n.as_str()) {
-                        new_name = Some(name.to_string());
-                    }
-                }
-            }
-        }
+    None
+}
+
+/// Write instance metadata back to instance.json in the given directory
+fn write_instance_metadata_file(instance_dir: &std::path::Path, metadata: &InstanceMetadata) -> Result<()> {
+    let metadata_path = instance_dir.join("instance.json");
+    let metadata_json = serde_json::to_string_pretty(metadata)?;
+    let mut file = fs::File::create(metadata_path)?;
+    file.write_all(metadata_json.as_bytes())?;
+    Ok(())
+}
+
+/// Try to fix instance name by reading from manifest.json or minecraftinstance.json
+fn try_fix_instance_name(instance_dir: &std::path::Path, metadata: &mut InstanceMetadata) -> Result<bool> {
+    // Only try to fix if name is same as ID (which implies it's using the folder name)
+    if metadata.name != metadata.id {
+        return Ok(false);
     }
 
-    if let Some(name) = new_name {
+    if let Some(name) = read_instance_name_from_manifests(instance_dir) {
         if name != metadata.name {
             println!("🔧 Auto-fixing instance name for {}: {} -> {}", metadata.id, metadata.name, name);
             metadata.name = name;
-            
-            // Save the updated metadata
-            let metadata_path = instance_dir.join("instance.json");
-            let metadata_json = serde_json::to_string_pretty(metadata)?;
-            let mut file = fs::File::create(metadata_path)?;
-            file.write_all(metadata_json.as_bytes())?;
-            
+            write_instance_metadata_file(instance_dir, metadata)?;
             return Ok(true);
         }
     }
@@ -446,6 +441,53 @@ fn try_fix_instance_name(instance_dir: &std::path::Path, metadata: &mut Instance
     Ok(false)
 }
 
+/// Force re-read the instance name from manifest.json or minecraftinstance.json,
+/// even if the current display name differs from the ID.
+/// Updates both instance.json and the cached meta/modpacks/{id}.json used by the UI.
+/// Returns the (old, new) names; they are equal if nothing changed.
+pub async fn fix_instance_name(modpack_id: &str) -> Result<(String, String)> {
+    let instance_dir = get_instance_dir(modpack_id)?;
+    if !instance_dir.join("instance.json").exists() {
+        return Err(anyhow!("Instance {} not found", modpack_id));
+    }
+
+    let meta_dir = get_launcher_data_dir()?.join("meta").join("modpacks");
+    fix_instance_name_in_dir(&instance_dir, &meta_dir)
+}
+
+/// Re-read the instance name for the instance stored in `instance_dir`,
+/// keeping the cached modpack metadata in `meta_dir` in sync
+fn fix_instance_name_in_dir(instance_dir: &std::path::Path, meta_dir: &std::path::Path) -> Result<(String, String)> {
+    let metadata_content = fs::read_to_string(instance_dir.join("instance.json"))?;
+    let mut metadata: InstanceMetadata = serde_json::from_str(&metadata_content)?;
+
+    let new_name = read_instance_name_from_manifests(instance_dir)
+        .ok_or_else(|| anyhow!("No name found in manifest.json or minecraftinstance.json for instance {}", metadata.id))?;
+
+    let old_name = metadata.name.clone();
+    if new_name != old_name {
+        println!("🔧 Fixing instance name for {}: {} -> {}", metadata.id, old_name, new_name);
+        metadata.name = new_name.clone();
+        write_instance_metadata_file(instance_dir, &metadata)?;
+    }
+
+    // The modpack list prefers the cached name, so merge the fixed name in there too
+    let cache_path = meta_dir.join(format!("{}.json", metadata.id));
+    if cache_path.exists() {
+        let content = fs::read_to_string(&cache_path)?;
+        let mut cache_data: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| anyhow!("Failed to parse modpack metadata: {}", e))?;
+
+        if let serde_json::Value::Object(ref mut map) = cache_data {
+            map.insert("name".to_string(), serde_json::Value::String(new_name.clone()));
+        }
+
+        fs::write(&cache_path, serde_json::to_string_pretty(&cache_data)?)?;
+    }
+
+    Ok((old_name, new_name))
+}
+
 /// List all installed instances
 #[allow(dead_code)]
 pub async fn list_instances() -> Result<Vec<InstanceMetadata>> {
@@ -820,4 +862,81 @@ pub async fn save_modpack_image(
     }
 
     Ok(())
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn write_metadata(dir: &Path, id: &str, name: &str) {
+        let metadata = create_instance_metadata(
+            id.to_string(),
+            name.to_string(),
+            "1.0.0".to_string(),
+            "forge".to_string(),
+            "47.2.0".to_string(),
+            "1.20.1".to_string(),
+        );
+        write_instance_metadata_file(dir, &metadata).unwrap();
+    }
+
+    fn read_name(dir: &Path) -> String {
+        let content = fs::read_to_string(dir.join("instance.json")).unwrap();
+        serde_json::from_str::<InstanceMetadata>(&content).unwrap().name
+    }
+
+    #[test]
+    fn fix_instance_name_overwrites_name_that_differs_from_id() {
+        let instance = tempfile::tempdir().unwrap();
+        let meta = tempfile::tempdir().unwrap();
+        write_metadata(instance.path(), "pack-id", "Weird Folder Name");
+        fs::write(instance.path().join("manifest.json"), r#"{"name": "My Pack"}"#).unwrap();
+
+        let (old, new) = fix_instance_name_in_dir(instance.path(), meta.path()).unwrap();
+
+        assert_eq!(old, "Weird Folder Name");
+        assert_eq!(new, "My Pack");
+        assert_eq!(read_name(instance.path()), "My Pack");
+    }
+
+    #[test]
+    fn fix_instance_name_falls_back_to_minecraftinstance_json() {
+        let instance = tempfile::tempdir().unwrap();
+        let meta = tempfile::tempdir().unwrap();
+        write_metadata(instance.path(), "pack-id", "pack-id");
+        fs::write(instance.path().join("minecraftinstance.json"), r#"{"name": "CurseForge Pack"}"#).unwrap();
+
+        let (old, new) = fix_instance_name_in_dir(instance.path(), meta.path()).unwrap();
+
+        assert_eq!(old, "pack-id");
+        assert_eq!(new, "CurseForge Pack");
+        assert_eq!(read_name(instance.path()), "CurseForge Pack");
+    }
+
+    #[test]
+    fn fix_instance_name_errors_without_manifests() {
+        let instance = tempfile::tempdir().unwrap();
+        let meta = tempfile::tempdir().unwrap();
+        write_metadata(instance.path(), "pack-id", "Some Name");
+
+        assert!(fix_instance_name_in_dir(instance.path(), meta.path()).is_err());
+        assert_eq!(read_name(instance.path()), "Some Name");
+    }
+
+    #[test]
+    fn fix_instance_name_updates_cached_modpack_name() {
+        let instance = tempfile::tempdir().unwrap();
+        let meta = tempfile::tempdir().unwrap();
+        write_metadata(instance.path(), "pack-id", "Weird Folder Name");
+        fs::write(instance.path().join("manifest.json"), r#"{"name": "My Pack"}"#).unwrap();
+        let cache_path = meta.path().join("pack-id.json");
+        fs::write(&cache_path, r#"{"name": "Weird Folder Name", "logo": "logo.png"}"#).unwrap();
+
+        fix_instance_name_in_dir(instance.path(), meta.path()).unwrap();
+
+        let cache: serde_json::Value = serde_json::from_str(&fs::read_to_string(&cache_path).unwrap()).unwrap();
+        assert_eq!(cache["name"], "My Pack");
+        assert_eq!(cache["logo"], "logo.png");
+    }
+}
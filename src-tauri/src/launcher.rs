@@ -1,6 +1,6 @@
 use crate::{Modpack, InstanceMetadata, UserSettings, filesystem, minecraft, meta::{MetaDirectories, InstanceDirectories}};
 use tauri::AppHandle;
-use crate::modpack::{extract_zip, curseforge, modrinth};
+use crate::modpack::{extract_zip_to_instance, curseforge, modrinth};
 use crate::utils::{cleanup_temp_file, download_file};
 use std::collections::HashSet;
 use anyhow::{Result, anyhow};
@@ -33,7 +33,7 @@ pub async fn install_modpack(modpack: Modpack) -> Result<()> {
     
     // Extract modpack
     println!("Extracting instance to: {}", instance_dir.display());
-    extract_zip(&temp_zip_path, &instance_dir)?;
+    extract_zip_to_instance(&temp_zip_path, &instance_dir)?;
     
     // Clean up temporary file
     if temp_zip_path.exists() {
@@ -437,9 +437,10 @@ where
             recommended_ram_from_manifest = recommended_ram;
             failed_mods
         } else {
-            // Regular ZIP modpack (no manifest)
+            // Regular ZIP modpack (no manifest): a raw `mods/`, `config/`, ... folder zip,
+            // optionally wrapped in a single top-level folder which gets stripped
             emit_progress("progress.extractingModpack".to_string(), 85.0, "extracting_modpack".to_string());
-            extract_zip(&temp_zip_path, &instance_dirs.instance_dir)?;
+            extract_zip_to_instance(&temp_zip_path, &instance_dirs.instance_dir)?;
             Vec::new()
         };
         
@@ -731,6 +731,17 @@ async fn verify_instance_integrity(
     }
 }
 
+#[tauri::command]
+async fn fix_instance_name(modpack_id: String) -> Result<serde_json::Value, String> {
+    match filesystem::fix_instance_name(&modpack_id).await {
+        Ok((old_name, new_name)) => Ok(serde_json::json!({
+            "oldName": old_name,
+            "newName": new_name
+        })),
+        Err(e) => Err(format!("Failed to fix instance name: {}", e)),
+    }
+}
+
 #[tauri::command]
 async fn delete_instance(modpack_id: String) -> Result<(), String> {
     match filesystem::delete_instance(&modpack_id).await {
@@ -1482,6 +1493,7 @@ fn main() {
             save_modpack_metadata_json,
             get_file_as_data_url,
             update_instance_ram_settings,
+            fix_instance_name,
             get_local_modpacks,
             install_modpack,
             install_modpack_with_minecraft,
@@ -1,9 +1,100 @@
 use anyhow::{Result, anyhow};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use zip::ZipArchive;
 
+/// Top-level folders that only ever live directly in an instance root, used as markers
+/// when deciding whether a single top-level ZIP folder is a redundant wrapper.
+/// Folders like `saves/` or `datapacks/` are deliberately left out: they also appear
+/// inside world folders, so a world ZIP would otherwise be flattened into the instance.
+/// Names are matched exactly: Minecraft only loads lowercase folders, so flattening
+/// something like `MyPack/Mods/` would not make the content load anyway.
+const INSTANCE_ROOT_DIRS: &[&str] = &[
+    "mods",
+    "config",
+    "resourcepacks",
+    "shaderpacks",
+    "kubejs",
+    "defaultconfigs",
+];
+
+/// Files that only show up directly in an instance root
+const INSTANCE_ROOT_FILES: &[&str] = &[
+    "options.txt",
+];
+
 /// Extract a ZIP file using standard Rust zip library
 pub fn extract_zip(zip_path: &PathBuf, extract_to: &PathBuf) -> Result<()> {
+    extract_zip_internal(zip_path, extract_to, false)
+}
+
+/// Extract a plain (manifest-less) modpack ZIP into an instance root.
+/// If every entry lives under a single wrapping folder (e.g. `MyPack-1.2/mods/...`),
+/// that folder is stripped so `mods/`, `config/`, etc. land directly in the instance root.
+pub fn extract_zip_to_instance(zip_path: &Path, instance_dir: &Path) -> Result<()> {
+    extract_zip_internal(zip_path, instance_dir, true)
+}
+
+/// Archive/OS metadata entries that should not count as pack content
+fn is_ignored_entry(name: &str) -> bool {
+    name == "__MACOSX" || name == ".DS_Store" || name == "Thumbs.db"
+}
+
+/// Whether a name is something that lives directly in an instance root
+fn is_instance_root_entry(name: &str) -> bool {
+    INSTANCE_ROOT_DIRS.contains(&name) || INSTANCE_ROOT_FILES.contains(&name)
+}
+
+/// Decide whether `root` is a redundant wrapper, given the names directly inside it.
+/// Only strip when the wrapped content is recognisably an instance, so a lone
+/// `config/` folder or a folder of unrelated files stays where the pack author put it.
+fn is_redundant_wrapper<'a>(root: &str, children: impl IntoIterator<Item = &'a str>) -> bool {
+    !is_instance_root_entry(root) && children.into_iter().any(is_instance_root_entry)
+}
+
+/// Detect a single top-level folder that wraps every entry of a ZIP.
+/// Returns `None` when entries sit at the root, span several top-level items,
+/// or [`is_redundant_wrapper`] rejects the folder.
+pub fn find_wrapping_root_dir<S: AsRef<str>>(names: &[S]) -> Option<String> {
+    let mut root: Option<&str> = None;
+    let mut children: Vec<&str> = Vec::new();
+
+    for name in names {
+        let name = name.as_ref().trim_start_matches('/');
+        if name.is_empty() {
+            continue;
+        }
+
+        // Every entry must live inside a folder; a root-level file means no wrapper
+        let (first, rest) = match name.split_once('/') {
+            Some(parts) => parts,
+            None if is_ignored_entry(name) => continue,
+            None => return None,
+        };
+
+        if is_ignored_entry(first) {
+            continue;
+        }
+
+        match root {
+            None => root = Some(first),
+            Some(existing) if existing != first => return None,
+            _ => {}
+        }
+
+        if let Some(child) = rest.split('/').next().filter(|c| !c.is_empty()) {
+            children.push(child);
+        }
+    }
+
+    let root = root?;
+    if !is_redundant_wrapper(root, children) {
+        return None;
+    }
+
+    Some(root.to_string())
+}
+
+fn extract_zip_internal(zip_path: &Path, extract_to: &Path, strip_wrapper: bool) -> Result<()> {
     // Validate ZIP file exists and is readable
     if !zip_path.exists() {
         return Err(anyhow!("ZIP file not found: {}", zip_path.display()));
@@ -34,12 +125,36 @@ pub fn extract_zip(zip_path: &PathBuf, extract_to: &PathBuf) -> Result<()> {
     let mut archive = ZipArchive::new(std::fs::File::open(zip_path)?)
         .map_err(|e| anyhow!("Failed to read ZIP archive: {}", e))?;
     
+    // Detect a redundant wrapping folder only once the archive is known to be valid
+    let wrapping_root = if strip_wrapper {
+        let names: Vec<&str> = archive.file_names().collect();
+        find_wrapping_root_dir(&names)
+    } else {
+        None
+    };
+    
+    if let Some(root) = &wrapping_root {
+        println!("📦 Stripping wrapping folder '{}' from modpack ZIP", root);
+    }
+    let strip_prefix = wrapping_root.as_deref();
+    
     // Collect file info (index and name) to avoid repeated string searches (O(1) lookup vs O(n))
     let file_info: Vec<(usize, String)> = (0..archive.len())
         .filter_map(|i| {
             if let Ok(file) = archive.by_index(i) {
                 if let Some(name) = file.enclosed_name() {
-                    return Some((i, name.to_string_lossy().into_owned()));
+                    let name = name.to_string_lossy().into_owned();
+                    return match strip_prefix {
+                        Some(prefix) => {
+                            // Drop the wrapping folder itself and re-root everything beneath it
+                            let relative = Path::new(&name).strip_prefix(prefix).ok()?;
+                            if relative.as_os_str().is_empty() {
+                                return None;
+                            }
+                            Some((i, relative.to_string_lossy().into_owned()))
+                        }
+                        None => Some((i, name)),
+                    };
                 }
             }
             None
@@ -97,4 +212,187 @@ pub fn extract_zip(zip_path: &PathBuf, extract_to: &PathBuf) -> Result<()> {
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    /// Write a ZIP where names ending in `/` are directories and everything else is a file
+    fn write_zip(zip_path: &Path, names: &[&str]) {
+        let mut zip = ZipWriter::new(std::fs::File::create(zip_path).unwrap());
+        let options = SimpleFileOptions::default();
+        for name in names {
+            if name.ends_with('/') {
+                zip.add_directory(*name, options).unwrap();
+            } else {
+                zip.start_file(*name, options).unwrap();
+                zip.write_all(b"test").unwrap();
+            }
+        }
+        zip.finish().unwrap();
+    }
+
+    #[test]
+    fn root_level_folders_are_not_a_wrapper() {
+        let names = ["mods/", "mods/a.jar", "config/", "config/a.toml"];
+        assert_eq!(find_wrapping_root_dir(&names), None);
+    }
+
+    #[test]
+    fn single_wrapping_folder_is_detected() {
+        let names = [
+            "MyPack-1.2/",
+            "MyPack-1.2/mods/",
+            "MyPack-1.2/mods/a.jar",
+            "MyPack-1.2/config/a.toml",
+        ];
+        assert_eq!(find_wrapping_root_dir(&names), Some("MyPack-1.2".to_string()));
+    }
+
+    #[test]
+    fn extracts_root_level_folder_zip_as_is() {
+        let dir = tempfile::tempdir().unwrap();
+        let zip_path = dir.path().join("pack.zip");
+        let instance_dir = dir.path().join("instance");
+        write_zip(&zip_path, &["mods/", "mods/a.jar", "config/", "config/a.toml"]);
+
+        extract_zip_to_instance(&zip_path, &instance_dir).unwrap();
+
+        assert!(instance_dir.join("mods").join("a.jar").is_file());
+        assert!(instance_dir.join("config").join("a.toml").is_file());
+    }
+
+    #[test]
+    fn extracts_wrapped_zip_with_wrapper_stripped() {
+        let dir = tempfile::tempdir().unwrap();
+        let zip_path = dir.path().join("pack.zip");
+        let instance_dir = dir.path().join("instance");
+        write_zip(&zip_path, &[
+            "MyPack-1.2/",
+            "MyPack-1.2/mods/",
+            "MyPack-1.2/mods/a.jar",
+            "MyPack-1.2/config/a.toml",
+        ]);
+
+        extract_zip_to_instance(&zip_path, &instance_dir).unwrap();
+
+        assert!(instance_dir.join("mods").join("a.jar").is_file());
+        assert!(instance_dir.join("config").join("a.toml").is_file());
+        assert!(!instance_dir.join("MyPack-1.2").exists());
+    }
+
+    #[test]
+    fn missing_zip_reports_not_found() {
+        let dir = tempfile::tempdir().unwrap();
+        let zip_path = dir.path().join("missing.zip");
+
+        let error = extract_zip_to_instance(&zip_path, &dir.path().join("instance")).unwrap_err();
+
+        assert!(error.to_string().contains("ZIP file not found"));
+    }
+
+    #[test]
+    fn lone_instance_folder_is_not_a_wrapper() {
+        let names = ["config/", "config/a.toml", "config/mods/b.toml"];
+        assert_eq!(find_wrapping_root_dir(&names), None);
+    }
+
+    #[test]
+    fn folder_without_instance_content_is_not_a_wrapper() {
+        let names = ["Screenshots/", "Screenshots/a.png", "Screenshots/readme.txt"];
+        assert_eq!(find_wrapping_root_dir(&names), None);
+    }
+
+    #[test]
+    fn world_folder_with_datapacks_is_not_a_wrapper() {
+        let names = [
+            "MyWorld/",
+            "MyWorld/level.dat",
+            "MyWorld/region/r.0.0.mca",
+            "MyWorld/datapacks/",
+            "MyWorld/datapacks/pack.zip",
+        ];
+        assert_eq!(find_wrapping_root_dir(&names), None);
+    }
+
+    #[test]
+    fn extracts_world_zip_without_flattening() {
+        let dir = tempfile::tempdir().unwrap();
+        let zip_path = dir.path().join("world.zip");
+        let instance_dir = dir.path().join("instance");
+        write_zip(&zip_path, &["MyWorld/level.dat", "MyWorld/datapacks/pack.zip"]);
+
+        extract_zip_to_instance(&zip_path, &instance_dir).unwrap();
+
+        assert!(instance_dir.join("MyWorld").join("level.dat").is_file());
+        assert!(!instance_dir.join("level.dat").exists());
+    }
+
+    #[test]
+    fn root_level_file_next_to_wrapper_disables_stripping() {
+        let names = ["README.txt", "MyPack/", "MyPack/mods/a.jar"];
+        assert_eq!(find_wrapping_root_dir(&names), None);
+    }
+
+    #[test]
+    fn os_metadata_entries_are_ignored() {
+        let names = [
+            "__MACOSX/",
+            "__MACOSX/MyPack/._a.jar",
+            ".DS_Store",
+            "MyPack/",
+            "MyPack/.DS_Store",
+            "MyPack/mods/a.jar",
+        ];
+        assert_eq!(find_wrapping_root_dir(&names), Some("MyPack".to_string()));
+    }
+
+    #[test]
+    fn instance_folder_names_are_case_sensitive() {
+        let names = ["MyPack/", "MyPack/Mods/a.jar"];
+        assert_eq!(find_wrapping_root_dir(&names), None);
+    }
+
+    #[test]
+    fn double_wrapped_folder_is_left_alone() {
+        let names = ["MyPack/", "MyPack/MyPack/", "MyPack/MyPack/mods/a.jar"];
+        assert_eq!(find_wrapping_root_dir(&names), None);
+    }
+
+    #[test]
+    fn extracts_wrapped_zip_with_child_named_like_wrapper() {
+        let dir = tempfile::tempdir().unwrap();
+        let zip_path = dir.path().join("pack.zip");
+        let instance_dir = dir.path().join("instance");
+        write_zip(&zip_path, &[
+            "MyPack/",
+            "MyPack/mods/a.jar",
+            "MyPack/MyPack/notes.txt",
+            "__MACOSX/MyPack/._a.jar",
+        ]);
+
+        extract_zip_to_instance(&zip_path, &instance_dir).unwrap();
+
+        assert!(instance_dir.join("mods").join("a.jar").is_file());
+        assert!(instance_dir.join("MyPack").join("notes.txt").is_file());
+        assert!(!instance_dir.join("MyPack").join("mods").exists());
+        assert!(!instance_dir.join("__MACOSX").exists());
+    }
+
+    #[test]
+    fn extracts_lone_config_folder_without_stripping() {
+        let dir = tempfile::tempdir().unwrap();
+        let zip_path = dir.path().join("pack.zip");
+        let instance_dir = dir.path().join("instance");
+        write_zip(&zip_path, &["config/", "config/a.toml"]);
+
+        extract_zip_to_instance(&zip_path, &instance_dir).unwrap();
+
+        assert!(instance_dir.join("config").join("a.toml").is_file());
+        assert!(!instance_dir.join("a.toml").exists());
+    }
+}
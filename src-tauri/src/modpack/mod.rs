@@ -3,4 +3,4 @@ pub mod modrinth;
 pub mod extraction;
 pub mod integrity;
  
-pub use extraction::extract_zip; 
\ No newline at end of file
+pub use extraction::{extract_zip, extract_zip_to_instance}; 
\ No newline at end of file
@@ -6,6 +6,7 @@ import { invoke } from '@tauri-apps/api/core';
 import { appDataDir } from '@tauri-apps/api/path';
 import toast from 'react-hot-toast';
 import { useLauncher } from '../../contexts/LauncherContext';
+import LauncherService from '../../services/launcherService';
 
 interface ProfileOptionsModalProps {
   modpackId: string;
@@ -66,6 +67,7 @@ const ProfileOptionsModal: React.FC<ProfileOptionsModalProps> = ({
   const [isRepairing, setIsRepairing] = useState(false);
   const [showReinstallConfirm, setShowReinstallConfirm] = useState(false);
   const [isReinstalling, setIsReinstalling] = useState(false);
+  const [isRestoringName, setIsRestoringName] = useState(false);
 
   // Protection Mode State
   const [allowCustomMods, setAllowCustomMods] = useState(metadata?.allow_custom_mods ?? true);
@@ -310,6 +312,30 @@ const ProfileOptionsModal: React.FC<ProfileOptionsModalProps> = ({
     }
   };
 
+  const handleRestoreName = async () => {
+    setIsRestoringName(true);
+    try {
+      const { newName } = await LauncherService.getInstance().fixInstanceName(modpackId);
+      setDisplayName(newName);
+      setEditingName(false);
+      toast.success(t('profileOptions.restoreName.success', { name: newName }));
+
+      if (onModpackUpdated) {
+        onModpackUpdated({ name: newName });
+      }
+      if (onSaveComplete) {
+        onSaveComplete();
+      }
+    } catch (error) {
+      console.error('Failed to restore instance name:', error);
+      toast.error(t('profileOptions.restoreName.failed', {
+        error: error instanceof Error ? error.message : String(error)
+      }));
+    } finally {
+      setIsRestoringName(false);
+    }
+  };
+
   const handleImageUpload = (imageType: 'logo' | 'banner', file: File) => {
     // Create preview URL
     const reader = new FileReader();
@@ -371,6 +397,15 @@ const ProfileOptionsModal: React.FC<ProfileOptionsModalProps> = ({
                 <span className="text-white">{displayName}</span>
               </div>
             )}
+            <button
+              onClick={handleRestoreName}
+              disabled={isRestoringName}
+              title={t('profileOptions.restoreName.hint')}
+              className="mt-2 flex items-center gap-2 text-xs text-lumina-400 hover:text-lumina-300 disabled:opacity-50 transition-colors"
+            >
+              <RefreshCcw className={`w-3 h-3 ${isRestoringName ? 'animate-spin' : ''}`} />
+              {t('profileOptions.restoreName.button')}
+            </button>
           </div>
         )}
 
@@ -1190,6 +1190,22 @@ class LauncherService {
     }
   }
 
+  async fixInstanceName(modpackId: string): Promise<{ oldName: string; newName: string }> {
+    try {
+      console.log('🔧 LauncherService: Fixing instance name for', modpackId);
+      const result = await safeInvoke<{ oldName: string; newName: string }>('fix_instance_name', { modpackId });
+      console.log('✅ LauncherService: Instance name fixed:', result.oldName, '->', result.newName);
+      return result;
+    } catch (error) {
+      console.error('❌ LauncherService: Error fixing instance name:', error);
+      if (!isTauriContext()) {
+        throw new Error('Esta función requiere ejecutar la aplicación con Tauri. Por favor, usa "npm run tauri:dev" en lugar de "npm run dev".');
+      }
+      // Surface the backend message (e.g. no name found in manifest.json/minecraftinstance.json)
+      throw new Error(error instanceof Error ? error.message : String(error));
+    }
+  }
+
   async openInstanceFolder(modpackId: string): Promise<void> {
     try {
       console.log('📂 LauncherService: Opening instance folder for', modpackId);